@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use bevy::{
     prelude::*,
     render::camera::ScalingMode,
@@ -9,7 +11,8 @@ use bevy_rand::prelude::GlobalEntropy;
 use rand_core::RngCore;
 
 use plugins::dl_window::DullLifeWindowPlugin;
-use world::DullWorld;
+use plugins::gpu_compute::{GpuComputePlugin, SimBackend};
+use world::{DullWorld, Rule};
 
 mod plugins;
 mod world;
@@ -17,15 +20,42 @@ mod world;
 const ROWS: usize = 120;
 const COLS: usize = 240;
 const THRESHOLD_FOR_INITIAL_LIFE: u32 = (u32::MAX as f32 * 0.3) as u32;
+/// The active automaton rulestring. Swap this to e.g. `"B36/S23"` for
+/// HighLife or `"B2/S"` for Seeds.
+const RULESTRING: &str = "B3/S23";
+/// Number of cell states. `2` is plain alive/dead Conway; anything higher
+/// makes cells fade through dying states instead of vanishing outright.
+const STATES: u8 = 4;
+
+const CELL_SIZE: f32 = 10.;
+
+const MIN_STEP_INTERVAL: f32 = 0.05;
+const MAX_STEP_INTERVAL: f32 = 2.0;
+const STEP_INTERVAL_ADJUSTMENT: f32 = 0.05;
+
+const SAVE_PATH: &str = "dull_life_save.json";
 
 #[derive(Resource)]
 struct StepTimer(Timer);
 
-#[derive(Resource)]
-struct CellDeadColor(Handle<ColorMaterial>);
+/// Color materials indexed by cell age, from youngest (live) to oldest
+/// (dead), interpolated between live and dead colors.
+#[derive(Resource, Clone)]
+struct CellColorRamp(Vec<Handle<ColorMaterial>>);
 
-#[derive(Resource)]
-struct CellLiveColor(Handle<ColorMaterial>);
+impl CellColorRamp {
+    fn material_for_state(&self, state: u8) -> Handle<ColorMaterial> {
+        self.0[state as usize].clone()
+    }
+}
+
+/// Tracks whether the simulation is paused and whether a single manual
+/// step has been requested while paused.
+#[derive(Resource, Default)]
+struct SimState {
+    paused: bool,
+    dirty: bool,
+}
 
 #[derive(Component)]
 struct Cell {
@@ -39,14 +69,8 @@ fn setup_world(
     mut materials: ResMut<Assets<ColorMaterial>>,
     mut rng: ResMut<GlobalEntropy<WyRand>>,
 ) {
-    let live_color_handle = materials.add(Color::GREEN);
-    let dead_color_handle = materials.add(Color::PURPLE);
-
-    let cell_live_color = CellLiveColor(live_color_handle.clone());
-    let cell_dead_color = CellDeadColor(dead_color_handle.clone());
-
-    commands.insert_resource(cell_live_color);
-    commands.insert_resource(cell_dead_color);
+    let color_ramp = CellColorRamp(build_color_ramp(&mut materials, STATES));
+    commands.insert_resource(color_ramp.clone());
 
     let mut random_world: Vec<Vec<u8>> = vec![vec![0; ROWS]; COLS];
     for i in 0..COLS {
@@ -57,24 +81,65 @@ fn setup_world(
             }
         }
     }
-    let dull_world =
-        DullWorld::from_config(random_world).expect("initial world creation to succeed");
+    let rule = Rule::from_rulestring(RULESTRING).expect("RULESTRING to be a valid rulestring");
+    println!("Running with rulestring {RULESTRING}");
+    let dull_world = DullWorld::from_config_with_rule_and_states(random_world, rule, STATES)
+        .expect("initial world creation to succeed");
+
+    spawn_cell_grid(&mut commands, &mut meshes, &color_ramp, dull_world);
+
+    // Camera
+    let mut camera = Camera2dBundle::default();
+    camera.projection.scaling_mode = ScalingMode::FixedVertical(1000.0);
+    commands.spawn(camera);
+}
+
+/// Builds one color per cell state, linearly interpolated from the live
+/// color at state 0 to the dead color at the final state.
+fn build_color_ramp(
+    materials: &mut Assets<ColorMaterial>,
+    states: u8,
+) -> Vec<Handle<ColorMaterial>> {
+    let live = Color::GREEN.as_rgba_f32();
+    let dead = Color::PURPLE.as_rgba_f32();
 
+    (0..states)
+        .map(|state| {
+            let t = state as f32 / (states - 1) as f32;
+            let lerp_channel = |index: usize| live[index] + (dead[index] - live[index]) * t;
+            materials.add(Color::rgba(
+                lerp_channel(0),
+                lerp_channel(1),
+                lerp_channel(2),
+                lerp_channel(3),
+            ))
+        })
+        .collect()
+}
+
+/// Spawns one `Cell` entity per grid position colored for its current
+/// state, plus the `DullWorld` entity itself. Used both on startup and
+/// when respawning the grid after a load.
+fn spawn_cell_grid(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    color_ramp: &CellColorRamp,
+    dull_world: DullWorld,
+) {
     let (row_len, col_len) = dull_world.dimensions();
 
     for row_index in 0..row_len {
         for col_index in 0..col_len {
             commands.spawn((
                 MaterialMesh2dBundle {
-                    mesh: Mesh2dHandle(meshes.add(Rectangle::new(10., 10.))),
-                    material: if dull_world.is_live(row_index, col_index) {
-                        live_color_handle.clone()
-                    } else {
-                        dead_color_handle.clone()
-                    },
+                    mesh: Mesh2dHandle(meshes.add(Rectangle::new(CELL_SIZE, CELL_SIZE))),
+                    material: color_ramp
+                        .material_for_state(
+                            dull_world.cell_state(row_index as i64, col_index as i64),
+                        ),
                     transform: Transform::from_xyz(
-                        (row_index as f32 - row_len as f32 / 2.) * 10.,
-                        (col_index as f32 - col_len as f32 / 2.) * 10.,
+                        (row_index as f32 - row_len as f32 / 2.) * CELL_SIZE,
+                        (col_index as f32 - col_len as f32 / 2.) * CELL_SIZE,
                         0.0,
                     ),
                     ..default()
@@ -88,15 +153,173 @@ fn setup_world(
     }
 
     commands.spawn(dull_world);
+}
 
-    // Camera
-    let mut camera = Camera2dBundle::default();
-    camera.projection.scaling_mode = ScalingMode::FixedVertical(1000.0);
-    commands.spawn(camera);
+/// Maps a cursor screen position back to the `(row_index, col_index)` of the
+/// `Cell` it sits over, toggles that cell on left-click, and handles
+/// pause/resume, single-stepping, step-interval adjustment, and (Tab)
+/// switching between the CPU and GPU compute backends.
+fn handle_input(
+    mouse_button_input: Res<Input<MouseButton>>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mut timer: ResMut<StepTimer>,
+    mut sim_state: ResMut<SimState>,
+    mut sim_backend: ResMut<SimBackend>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    mut dull_world_query: Query<&mut DullWorld>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        sim_state.paused = !sim_state.paused;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Tab) {
+        let next_backend = match *sim_backend {
+            SimBackend::Cpu => SimBackend::Gpu,
+            SimBackend::Gpu => SimBackend::Cpu,
+        };
+
+        // The GPU shader only tracks alive/dead; switching a Generations-style
+        // world (more than 2 states) onto it would silently flatten every
+        // dying cell to fully dead.
+        let gpu_incompatible = next_backend == SimBackend::Gpu
+            && dull_world_query
+                .get_single()
+                .map(|dull_world| dull_world.states() > 2)
+                .unwrap_or(false);
+
+        if gpu_incompatible {
+            println!("Cannot switch to the GPU backend: it only supports 2-state (binary) worlds");
+        } else {
+            *sim_backend = next_backend;
+            println!("Switched simulation backend to {sim_backend:?}");
+        }
+    }
+
+    if sim_state.paused && keyboard_input.just_pressed(KeyCode::Right) {
+        sim_state.dirty = true;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Equals) {
+        let next = (timer.0.duration().as_secs_f32() - STEP_INTERVAL_ADJUSTMENT)
+            .max(MIN_STEP_INTERVAL);
+        timer.0.set_duration(std::time::Duration::from_secs_f32(next));
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Minus) {
+        let next = (timer.0.duration().as_secs_f32() + STEP_INTERVAL_ADJUSTMENT)
+            .min(MAX_STEP_INTERVAL);
+        timer.0.set_duration(std::time::Duration::from_secs_f32(next));
+    }
+
+    if !mouse_button_input.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let Some(cursor_position) = window.cursor_position() else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = camera_query.get_single() else {
+        return;
+    };
+    let Some(world_position) = camera.viewport_to_world_2d(camera_transform, cursor_position)
+    else {
+        return;
+    };
+    let Ok(mut dull_world) = dull_world_query.get_single_mut() else {
+        return;
+    };
+
+    let (row_len, col_len) = dull_world.dimensions();
+    let row_index = (world_position.x / CELL_SIZE + row_len as f32 / 2.).round();
+    let col_index = (world_position.y / CELL_SIZE + col_len as f32 / 2.).round();
+
+    if row_index < 0. || col_index < 0. {
+        return;
+    }
+
+    let row_index = row_index as usize;
+    let col_index = col_index as usize;
+
+    if row_index >= row_len || col_index >= col_len {
+        return;
+    }
+
+    let alive = !dull_world.is_live(row_index as i64, col_index as i64);
+    dull_world.set_cell(row_index as i64, col_index as i64, alive);
+}
+
+/// Handles Ctrl+S to save the current `DullWorld` to `SAVE_PATH` and
+/// Ctrl+O to load it back, respawning the `Cell` entity grid to match.
+fn handle_save_load_input(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    color_ramp: Res<CellColorRamp>,
+    cell_query: Query<Entity, With<Cell>>,
+    dull_world_query: Query<(Entity, &DullWorld)>,
+) {
+    let ctrl_held = keyboard_input.pressed(KeyCode::ControlLeft)
+        || keyboard_input.pressed(KeyCode::ControlRight);
+    if !ctrl_held {
+        return;
+    }
+
+    let Ok((world_entity, dull_world)) = dull_world_query.get_single() else {
+        return;
+    };
+
+    if keyboard_input.just_pressed(KeyCode::S) {
+        if let Err(error) = dull_world.save_to_path(Path::new(SAVE_PATH)) {
+            eprintln!("Failed to save world: {error}");
+        }
+        return;
+    }
+
+    if !keyboard_input.just_pressed(KeyCode::O) {
+        return;
+    }
+
+    let loaded_world = match DullWorld::load_from_path(Path::new(SAVE_PATH)) {
+        Ok(loaded_world) => loaded_world,
+        Err(error) => {
+            eprintln!("Failed to load world: {error}");
+            return;
+        }
+    };
+
+    for cell_entity in &cell_query {
+        commands.entity(cell_entity).despawn();
+    }
+    commands.entity(world_entity).despawn();
+
+    spawn_cell_grid(&mut commands, &mut meshes, &color_ramp, loaded_world);
 }
 
-fn update_world(time: Res<Time>, mut timer: ResMut<StepTimer>, mut query: Query<&mut DullWorld>) {
-    if timer.0.tick(time.delta()).just_finished() {
+/// Steps the simulation on the CPU. When `SimBackend::Gpu` is active, the
+/// compute shader in `GpuComputePlugin` advances the board instead, and
+/// `sync_gpu_result` copies its output back into `DullWorld` each frame.
+fn update_world(
+    time: Res<Time>,
+    mut timer: ResMut<StepTimer>,
+    mut sim_state: ResMut<SimState>,
+    sim_backend: Res<SimBackend>,
+    mut query: Query<&mut DullWorld>,
+) {
+    if *sim_backend != SimBackend::Cpu {
+        return;
+    }
+
+    let should_step = if sim_state.paused {
+        std::mem::take(&mut sim_state.dirty)
+    } else {
+        timer.0.tick(time.delta()).just_finished()
+    };
+
+    if should_step {
         for mut dull_world in &mut query {
             dull_world.step();
         }
@@ -106,25 +329,29 @@ fn update_world(time: Res<Time>, mut timer: ResMut<StepTimer>, mut query: Query<
 fn render_world(
     mut q_entities: Query<(&mut Handle<ColorMaterial>, &Cell)>,
     q_dull_world: Query<&DullWorld>,
-    q_dead_cell_color: Res<CellDeadColor>,
-    q_live_cell_color: Res<CellLiveColor>,
+    q_color_ramp: Res<CellColorRamp>,
 ) {
     let dull_world = q_dull_world.iter().next().unwrap();
     for (mut entity, cell) in q_entities.iter_mut() {
-        if dull_world.is_live(cell.row_index, cell.col_index) {
-            *entity = q_dead_cell_color.0.clone();
-        } else {
-            *entity = q_live_cell_color.0.clone();
-        };
+        let state = dull_world.cell_state(cell.row_index as i64, cell.col_index as i64);
+        *entity = q_color_ramp.material_for_state(state);
     }
 }
 
 fn main() {
     App::new()
         .insert_resource(StepTimer(Timer::from_seconds(0.2, TimerMode::Repeating)))
-        .add_plugins((DullLifeWindowPlugin, EntropyPlugin::<WyRand>::default()))
+        .insert_resource(SimState::default())
+        .add_plugins((
+            DullLifeWindowPlugin,
+            EntropyPlugin::<WyRand>::default(),
+            GpuComputePlugin,
+        ))
         .add_systems(Startup, setup_world)
-        .add_systems(Update, update_world)
+        .add_systems(
+            Update,
+            (handle_input, handle_save_load_input, update_world).chain(),
+        )
         .add_systems(PostUpdate, render_world)
         .run();
 }
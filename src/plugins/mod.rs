@@ -0,0 +1,2 @@
+pub mod dl_window;
+pub mod gpu_compute;
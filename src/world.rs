@@ -1,49 +1,371 @@
+use std::path::Path;
+
 use bevy::{ecs::component::Component, utils::HashMap};
+use serde::{Deserialize, Serialize};
 
 const MIN_ROWS_ERROR: &str = "Grid row must have a minimum length of 2";
 const MIN_COLS_ERROR: &str = "Grid column must have a minimum length of 2";
 const COLS_LEN_CONSISTENCY_ERROR: &str = "All grid columns must be of the same length";
+const INVALID_RULESTRING_ERROR: &str =
+    "Rulestring must look like \"B<digits>/S<digits>\" with digits in 0..=8";
+const INVALID_RLE_ERROR: &str = "Malformed RLE pattern";
+const SAVE_ERROR: &str = "Failed to save world snapshot";
+const LOAD_ERROR: &str = "Failed to load world snapshot";
+const MIN_STATES_ERROR: &str = "A world must have at least 2 states";
 
 const MIN_ROWS: usize = 2;
 const MIN_COLS: usize = 2;
+const MIN_STATES: u8 = 2;
+
+/// Side length of one `Chunk` tile. Chosen to be small enough that a lone
+/// glider only ever touches a handful of tiles, and large enough that a
+/// typical on-screen pattern lives in one or two.
+const CHUNK_SIZE: i64 = 64;
 
-const ROW_PRIME: usize = 22283;
-const COL_PRIME: usize = 19709;
+const RULE_NEIGHBOR_COUNTS: usize = 9;
 
 type Grid = Vec<Vec<u8>>;
-type CellPosition = (usize, usize);
 type LivingCellsCount = u8;
+type RuleTable = [bool; RULE_NEIGHBOR_COUNTS];
+
+/// A cell's age: `0` is the youngest live state, `1..states - 2` are
+/// "dying" states it ages through after failing to survive, and
+/// `states - 1` is fully dead (and so never stored in a `Chunk`).
+type CellState = u8;
+
+/// A cell's position in the world's unbounded coordinate space. Unlike the
+/// grid the world was originally configured with, this is signed: the
+/// world grows outward in every direction instead of wrapping.
+type GlobalPosition = (i64, i64);
+
+/// Which `Chunk` a `GlobalPosition` falls into, found via `chunk_coord_of`.
+type ChunkCoord = (i64, i64);
+
+/// A position within a single chunk, in `0..CHUNK_SIZE`.
+type LocalPosition = (u8, u8);
+
+fn chunk_coord_of(position: GlobalPosition) -> ChunkCoord {
+    (
+        position.0.div_euclid(CHUNK_SIZE),
+        position.1.div_euclid(CHUNK_SIZE),
+    )
+}
+
+fn local_position_of(position: GlobalPosition) -> LocalPosition {
+    (
+        position.0.rem_euclid(CHUNK_SIZE) as u8,
+        position.1.rem_euclid(CHUNK_SIZE) as u8,
+    )
+}
+
+fn global_position_of(chunk_coord: ChunkCoord, local_position: LocalPosition) -> GlobalPosition {
+    (
+        chunk_coord.0 * CHUNK_SIZE + local_position.0 as i64,
+        chunk_coord.1 * CHUNK_SIZE + local_position.1 as i64,
+    )
+}
+
+/// One fixed-size tile of the otherwise-unbounded world. A cell missing
+/// from `cells` is fully dead, the same convention the old flat map used;
+/// a `Chunk` is only ever kept around while it has at least one entry, so
+/// `DullWorld::chunks` doubles as the set of "active" tiles to step.
+#[derive(Default)]
+struct Chunk {
+    cells: HashMap<LocalPosition, CellState>,
+}
+
+impl Chunk {
+    fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+}
+
+type ChunkMap = HashMap<ChunkCoord, Chunk>;
+
+fn insert_cell(chunks: &mut ChunkMap, position: GlobalPosition, state: CellState) {
+    chunks
+        .entry(chunk_coord_of(position))
+        .or_default()
+        .cells
+        .insert(local_position_of(position), state);
+}
+
+fn remove_cell(chunks: &mut ChunkMap, position: GlobalPosition) {
+    let chunk_coord = chunk_coord_of(position);
+    let Some(chunk) = chunks.get_mut(&chunk_coord) else {
+        return;
+    };
+
+    chunk.cells.remove(&local_position_of(position));
+    if chunk.is_empty() {
+        chunks.remove(&chunk_coord);
+    }
+}
+
+/// A birth/survival rulestring, e.g. `"B3/S23"` for Conway's Game of Life.
+///
+/// `birth[n]` is `true` when a dead cell with `n` live neighbors is born, and
+/// `survive[n]` is `true` when a live cell with `n` live neighbors stays alive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rule {
+    birth: RuleTable,
+    survive: RuleTable,
+}
+
+impl Rule {
+    /// Conway's Game of Life: B3/S23.
+    pub fn conway() -> Self {
+        Self::from_rulestring("B3/S23").expect("B3/S23 to be a valid rulestring")
+    }
+
+    /// Parses a standard rulestring such as `"B3/S23"` or `"B36/S23"`.
+    pub fn from_rulestring(rulestring: &str) -> Result<Self, String> {
+        let (birth_part, survive_part) = rulestring
+            .split_once('/')
+            .ok_or(INVALID_RULESTRING_ERROR)?;
+
+        let birth_digits = birth_part.strip_prefix('B').ok_or(INVALID_RULESTRING_ERROR)?;
+        let survive_digits = survive_part
+            .strip_prefix('S')
+            .ok_or(INVALID_RULESTRING_ERROR)?;
+
+        Ok(Self {
+            birth: parse_rule_table(birth_digits)?,
+            survive: parse_rule_table(survive_digits)?,
+        })
+    }
+
+    /// Renders the rule back into standard rulestring form, e.g. `"B3/S23"`.
+    pub fn to_rulestring(&self) -> String {
+        let digits = |table: &RuleTable| -> String {
+            (0..RULE_NEIGHBOR_COUNTS)
+                .filter(|&count| table[count])
+                .map(|count| count.to_string())
+                .collect()
+        };
 
-type Hash = usize;
-type LiveCellMap = HashMap<Hash, CellPosition>;
-type DeadCellMap = HashMap<Hash, (LivingCellsCount, CellPosition)>;
+        format!("B{}/S{}", digits(&self.birth), digits(&self.survive))
+    }
+
+    /// Whether a dead cell with `count` live neighbors is born under this
+    /// rule. Exposed so backends that don't share chunk storage directly
+    /// (e.g. the GPU compute path) can still pack the rule for themselves.
+    pub fn births(&self, count: usize) -> bool {
+        self.birth[count]
+    }
+
+    /// Whether a live cell with `count` live neighbors survives under this
+    /// rule. See [`Rule::births`].
+    pub fn survives(&self, count: usize) -> bool {
+        self.survive[count]
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule::conway()
+    }
+}
+
+fn parse_rule_table(digits: &str) -> Result<RuleTable, String> {
+    let mut table = [false; RULE_NEIGHBOR_COUNTS];
+
+    for digit in digits.chars() {
+        let count = digit
+            .to_digit(10)
+            .filter(|count| (*count as usize) < RULE_NEIGHBOR_COUNTS)
+            .ok_or(INVALID_RULESTRING_ERROR)?;
+        table[count as usize] = true;
+    }
+
+    Ok(table)
+}
 
 #[derive(Component)]
 pub struct DullWorld {
     rows: usize,
     cols: usize,
-    living_cells: LiveCellMap,
+    rule: Rule,
+    states: u8,
+    chunks: ChunkMap,
+}
+
+/// Sparse, serializable snapshot of a `DullWorld`: dimensions, the active
+/// rule and state count, and only the live/dying cells rather than the
+/// full grid.
+#[derive(Serialize, Deserialize)]
+struct DullWorldSnapshot {
+    rows: usize,
+    cols: usize,
+    rule: Rule,
+    states: u8,
+    cells: Vec<(CellState, GlobalPosition)>,
+}
+
+impl From<&DullWorld> for DullWorldSnapshot {
+    fn from(world: &DullWorld) -> Self {
+        let cells = world
+            .chunks
+            .iter()
+            .flat_map(|(&chunk_coord, chunk)| {
+                chunk
+                    .cells
+                    .iter()
+                    .map(move |(&local_position, &state)| {
+                        (state, global_position_of(chunk_coord, local_position))
+                    })
+            })
+            .collect();
+
+        Self {
+            rows: world.rows,
+            cols: world.cols,
+            rule: world.rule,
+            states: world.states,
+            cells,
+        }
+    }
+}
+
+impl TryFrom<DullWorldSnapshot> for DullWorld {
+    type Error = String;
+
+    fn try_from(snapshot: DullWorldSnapshot) -> Result<Self, Self::Error> {
+        if snapshot.rows < MIN_ROWS {
+            return Err(MIN_ROWS_ERROR.into());
+        }
+
+        if snapshot.cols < MIN_COLS {
+            return Err(MIN_COLS_ERROR.into());
+        }
+
+        if snapshot.states < MIN_STATES {
+            return Err(MIN_STATES_ERROR.into());
+        }
+
+        let mut chunks: ChunkMap = HashMap::new();
+        for (state, position) in snapshot.cells {
+            insert_cell(&mut chunks, position, state);
+        }
+
+        Ok(Self {
+            rows: snapshot.rows,
+            cols: snapshot.cols,
+            rule: snapshot.rule,
+            states: snapshot.states,
+            chunks,
+        })
+    }
+}
+
+impl Serialize for DullWorld {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DullWorldSnapshot::from(self).serialize(serializer)
+    }
 }
 
-fn build_living_cell_key(row_index: usize, col_index: usize) -> usize {
-    row_index * ROW_PRIME + col_index * COL_PRIME
+impl<'de> Deserialize<'de> for DullWorld {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        DullWorldSnapshot::deserialize(deserializer)
+            .and_then(|snapshot| DullWorld::try_from(snapshot).map_err(serde::de::Error::custom))
+    }
+}
+
+fn parse_rle_header(header: &str) -> Result<(usize, usize, Rule), String> {
+    let mut cols = None;
+    let mut rows = None;
+    let mut rule = Rule::default();
+
+    for part in header.split(',') {
+        let mut key_value = part.splitn(2, '=');
+        let key = key_value.next().ok_or(INVALID_RLE_ERROR)?.trim();
+        let value = key_value.next().ok_or(INVALID_RLE_ERROR)?.trim();
+
+        match key {
+            "x" => cols = value.parse::<usize>().ok(),
+            "y" => rows = value.parse::<usize>().ok(),
+            "rule" => rule = Rule::from_rulestring(value)?,
+            _ => {}
+        }
+    }
+
+    Ok((
+        cols.ok_or(INVALID_RLE_ERROR)?,
+        rows.ok_or(INVALID_RLE_ERROR)?,
+        rule,
+    ))
+}
+
+fn parse_rle_body(body: &str) -> Result<ChunkMap, String> {
+    let mut chunks: ChunkMap = HashMap::new();
+    let mut row_index = 0i64;
+    let mut col_index = 0i64;
+    let mut run_count: usize = 0;
+
+    for ch in body.chars() {
+        match ch {
+            '!' => break,
+            '0'..='9' => run_count = run_count * 10 + ch.to_digit(10).unwrap() as usize,
+            'b' | 'o' => {
+                let count = run_count.max(1);
+                if ch == 'o' {
+                    for offset in 0..count as i64 {
+                        insert_cell(&mut chunks, (row_index, col_index + offset), 0);
+                    }
+                }
+                col_index += count as i64;
+                run_count = 0;
+            }
+            '$' => {
+                row_index += run_count.max(1) as i64;
+                col_index = 0;
+                run_count = 0;
+            }
+            ch if ch.is_whitespace() => {}
+            _ => return Err(INVALID_RLE_ERROR.into()),
+        }
+    }
+
+    Ok(chunks)
+}
+
+/// Run-length encodes one row's dead/live pattern into `(count, tag)` pairs
+/// where `tag` is `'o'` for a live run and `'b'` for a dead run.
+fn run_length_encode_row(is_live: impl Fn(usize) -> bool, cols: usize) -> Vec<(usize, char)> {
+    let mut tokens = Vec::new();
+    let mut col_index = 0;
+
+    while col_index < cols {
+        let alive = is_live(col_index);
+        let run_start = col_index;
+        while col_index < cols && is_live(col_index) == alive {
+            col_index += 1;
+        }
+        tokens.push((col_index - run_start, if alive { 'o' } else { 'b' }));
+    }
+
+    tokens
+}
+
+fn push_rle_run(buffer: &mut String, count: usize, tag: char) {
+    if count > 1 {
+        buffer.push_str(&count.to_string());
+    }
+    buffer.push(tag);
 }
 
-fn build_map_from_grid(grid: &Grid) -> LiveCellMap {
-    let mut living_cells: LiveCellMap = HashMap::new();
+fn build_chunks_from_grid(grid: &Grid) -> ChunkMap {
+    let mut chunks: ChunkMap = HashMap::new();
 
     for (row_index, row) in grid.iter().enumerate() {
         for (col_index, col) in row.iter().enumerate() {
             if *col == 1 {
-                living_cells.insert(
-                    build_living_cell_key(row_index, col_index),
-                    (row_index, col_index),
-                );
+                insert_cell(&mut chunks, (row_index as i64, col_index as i64), 0);
             }
         }
     }
 
-    living_cells
+    chunks
 }
 
 impl DullWorld {
@@ -51,14 +373,42 @@ impl DullWorld {
         (self.rows, self.cols)
     }
 
-    pub fn get_living_cells(&self) -> Vec<CellPosition> {
-        self.living_cells
+    /// Positions of cells currently at state 0 (the youngest, fully live
+    /// state). Dying cells (state `1..states - 2`) are not included.
+    pub fn get_living_cells(&self) -> Vec<GlobalPosition> {
+        self.chunks
             .iter()
-            .map(|(_, position)| *position)
+            .flat_map(|(&chunk_coord, chunk)| {
+                chunk.cells.iter().filter_map(move |(&local_position, &state)| {
+                    (state == 0).then(|| global_position_of(chunk_coord, local_position))
+                })
+            })
             .collect()
     }
 
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// The number of states a cell can be in; `2` is the Conway special
+    /// case (alive or dead, no aging trail).
+    pub fn states(&self) -> u8 {
+        self.states
+    }
+
     pub fn from_config(grid: Grid) -> Result<Self, String> {
+        Self::from_config_with_rule(grid, Rule::default())
+    }
+
+    pub fn from_config_with_rule(grid: Grid, rule: Rule) -> Result<Self, String> {
+        Self::from_config_with_rule_and_states(grid, rule, MIN_STATES)
+    }
+
+    pub fn from_config_with_rule_and_states(
+        grid: Grid,
+        rule: Rule,
+        states: u8,
+    ) -> Result<Self, String> {
         let rows = grid.len();
 
         if rows < MIN_ROWS {
@@ -79,88 +429,252 @@ impl DullWorld {
             return Err(COLS_LEN_CONSISTENCY_ERROR.into());
         }
 
-        let living_cells = build_map_from_grid(&grid);
+        if states < MIN_STATES {
+            return Err(MIN_STATES_ERROR.into());
+        }
+
+        let chunks = build_chunks_from_grid(&grid);
 
         Ok(Self {
             rows,
             cols,
-            living_cells,
+            rule,
+            states,
+            chunks,
         })
     }
 
+    fn cell_state_at(&self, position: GlobalPosition) -> CellState {
+        self.chunks
+            .get(&chunk_coord_of(position))
+            .and_then(|chunk| chunk.cells.get(&local_position_of(position)))
+            .copied()
+            .unwrap_or(self.states - 1)
+    }
+
+    /// Counts the state-0 (fully live) neighbors of `position`, and, for
+    /// every dead neighbor found, bumps its count in
+    /// `dead_neighbor_counts` so `step` can later check it for a birth.
+    /// Neighbor positions outside every existing chunk are simply dead,
+    /// rather than wrapping back onto the opposite edge of a fixed grid.
     fn process_neighbors(
         &self,
-        row: usize,
-        col: usize,
-        dead_cells_neighboring_living_cells: &mut DeadCellMap,
+        position: GlobalPosition,
+        dead_neighbor_counts: &mut HashMap<GlobalPosition, LivingCellsCount>,
     ) -> LivingCellsCount {
-        let row_plus_one = (row + 1) % self.rows;
-        let row_minus_one = (row + self.rows - 1) % self.rows;
-        let col_plus_one = (col + 1) % self.cols;
-        let col_minus_one = (col + self.cols - 1) % self.cols;
+        let (row, col) = position;
+        let dead_state = self.states - 1;
 
         let neighbor_positions = [
-            (row_minus_one, col_minus_one),
-            (row_minus_one, col),
-            (row_minus_one, col_plus_one),
-            (row, col_minus_one),
-            (row, col_plus_one),
-            (row_plus_one, col_minus_one),
-            (row_plus_one, col),
-            (row_plus_one, col_plus_one),
+            (row - 1, col - 1),
+            (row - 1, col),
+            (row - 1, col + 1),
+            (row, col - 1),
+            (row, col + 1),
+            (row + 1, col - 1),
+            (row + 1, col),
+            (row + 1, col + 1),
         ];
 
+        // Only state-0 (fully live) neighbors count towards birth/survival;
+        // dying neighbors are neither living nor available to be born into.
         neighbor_positions
             .iter()
-            .fold(0, |living_neighbors_count, (row_index, col_index)| {
-                let next_key = build_living_cell_key(*row_index, *col_index);
-                if self.living_cells.contains_key(&next_key) {
-                    return living_neighbors_count + 1;
+            .fold(0, |living_neighbors_count, &neighbor_position| {
+                match self.cell_state_at(neighbor_position) {
+                    0 => living_neighbors_count + 1,
+                    state if state == dead_state => {
+                        *dead_neighbor_counts.entry(neighbor_position).or_insert(0) += 1;
+                        living_neighbors_count
+                    }
+                    _ => living_neighbors_count,
                 }
+            })
+    }
+
+    pub fn step(&mut self) {
+        let mut dead_neighbor_counts: HashMap<GlobalPosition, LivingCellsCount> = HashMap::new();
+        let mut next_chunks: ChunkMap = HashMap::new();
 
-                if let Some(entry) = dead_cells_neighboring_living_cells.get_mut(&next_key) {
-                    *entry = (entry.0 + 1, (*row_index, *col_index));
-                } else {
-                    dead_cells_neighboring_living_cells
-                        .insert(next_key, (1, (*row_index, *col_index)));
+        for (&chunk_coord, chunk) in self.chunks.iter() {
+            for (&local_position, &state) in chunk.cells.iter() {
+                let position = global_position_of(chunk_coord, local_position);
+
+                if state != 0 {
+                    let next_state = state + 1;
+                    if next_state < self.states - 1 {
+                        insert_cell(&mut next_chunks, position, next_state);
+                    }
+                    continue;
                 }
 
-                living_neighbors_count
-            })
+                let living_neighbors_count =
+                    self.process_neighbors(position, &mut dead_neighbor_counts);
+
+                if self.rule.survive[living_neighbors_count as usize] {
+                    insert_cell(&mut next_chunks, position, 0);
+                } else if self.states > MIN_STATES {
+                    insert_cell(&mut next_chunks, position, 1);
+                }
+            }
+        }
+
+        for (position, living_neighbors_count) in dead_neighbor_counts {
+            if self.rule.birth[living_neighbors_count as usize] {
+                insert_cell(&mut next_chunks, position, 0);
+            }
+        }
+
+        // Chunks that lost their last cell simply never get re-inserted
+        // above, so `next_chunks` only ever holds active tiles.
+        self.chunks = next_chunks;
     }
 
-    pub fn step(&mut self) {
-        let mut dead_cells_neighboring_living_cells: DeadCellMap = HashMap::new();
-        let mut next_generation: LiveCellMap = HashMap::new();
-
-        for (key, (row_index, col_index)) in self.living_cells.iter() {
-            let living_neighbors_count = self.process_neighbors(
-                *row_index,
-                *col_index,
-                &mut dead_cells_neighboring_living_cells,
-            );
+    pub fn is_live(&self, row: i64, col: i64) -> bool {
+        self.cell_state_at((row, col)) == 0
+    }
 
-            if living_neighbors_count != 2 && living_neighbors_count != 3 {
-                continue;
+    /// The cell's current age: `0` is fully alive, increasing values are
+    /// progressively more "dying", and `states() - 1` is fully dead.
+    pub fn cell_state(&self, row: i64, col: i64) -> u8 {
+        self.cell_state_at((row, col))
+    }
+
+    /// Sets a single cell alive (state 0) or fully dead, lazily allocating
+    /// (or, if it becomes empty, freeing) the chunk it falls in. Used by
+    /// interactive editing. Does not support setting an intermediate dying
+    /// state.
+    pub fn set_cell(&mut self, row: i64, col: i64, alive: bool) {
+        let position = (row, col);
+        if alive {
+            insert_cell(&mut self.chunks, position, 0);
+        } else {
+            remove_cell(&mut self.chunks, position);
+        }
+    }
+
+    /// Flattens the state-0 (fully live) cells within this world's
+    /// originally configured `rows * cols` box into a dense buffer of
+    /// `0`/`1` in row-major order. Cells born outside that box (the world
+    /// is otherwise unbounded) are not included. Used to upload the board
+    /// to the GPU compute backend.
+    pub fn to_dense(&self) -> Vec<u32> {
+        let mut dense = vec![0u32; self.rows * self.cols];
+        for row_index in 0..self.rows {
+            for col_index in 0..self.cols {
+                if self.is_live(row_index as i64, col_index as i64) {
+                    dense[row_index * self.cols + col_index] = 1;
+                }
             }
-            next_generation.insert(*key, (*row_index, *col_index));
+        }
+        dense
+    }
+
+    /// Sets every cell within this world's originally configured
+    /// `rows * cols` box from a dense buffer produced by `to_dense`, such
+    /// as one read back from the GPU compute backend. Cells outside that
+    /// box are left untouched.
+    pub fn load_dense(&mut self, dense: &[u32]) {
+        for row_index in 0..self.rows {
+            for col_index in 0..self.cols {
+                let alive = dense[row_index * self.cols + col_index] != 0;
+                self.set_cell(row_index as i64, col_index as i64, alive);
+            }
+        }
+    }
+
+    /// Parses a pattern in the standard Life RLE format: a header line
+    /// `x = <cols>, y = <rows>, rule = B3/S23` (the `rule` field is optional
+    /// and defaults to Conway), followed by a run-length encoded body of
+    /// `b`/`o`/`$` tokens terminated by `!`. Lines starting with `#` are
+    /// comments. Only live runs are materialized, so the result is sparse.
+    pub fn from_rle(input: &str) -> Result<Self, String> {
+        let mut lines = input
+            .lines()
+            .filter(|line| !line.trim_start().starts_with('#'));
+
+        let header = lines.next().ok_or(INVALID_RLE_ERROR)?;
+        let (cols, rows, rule) = parse_rle_header(header)?;
+
+        if rows < MIN_ROWS {
+            return Err(MIN_ROWS_ERROR.into());
+        }
+
+        if cols < MIN_COLS {
+            return Err(MIN_COLS_ERROR.into());
         }
 
-        for (key, (living_neighbors_count, (row_index, col_index))) in
-            dead_cells_neighboring_living_cells.iter()
-        {
-            if *living_neighbors_count != 3 {
+        let body: String = lines.collect();
+        let chunks = parse_rle_body(&body)?;
+
+        Ok(Self {
+            rows,
+            cols,
+            rule,
+            states: MIN_STATES,
+            chunks,
+        })
+    }
+
+    /// Encodes this world into the standard Life RLE format (see `from_rle`).
+    pub fn to_rle(&self) -> String {
+        let mut body = String::new();
+        let mut blank_rows_since_content = 0usize;
+        let mut emitted_a_row = false;
+
+        for row_index in 0..self.rows {
+            let mut row_tokens = run_length_encode_row(
+                |col_index| self.is_live(row_index as i64, col_index as i64),
+                self.cols,
+            );
+
+            if matches!(row_tokens.last(), Some((_, 'b'))) {
+                row_tokens.pop();
+            }
+
+            if row_tokens.is_empty() {
+                blank_rows_since_content += 1;
                 continue;
             }
-            next_generation.insert(*key, (*row_index, *col_index));
+
+            let row_gap = if emitted_a_row {
+                blank_rows_since_content + 1
+            } else {
+                blank_rows_since_content
+            };
+            if row_gap > 0 {
+                push_rle_run(&mut body, row_gap, '$');
+            }
+            blank_rows_since_content = 0;
+            emitted_a_row = true;
+
+            for (count, tag) in row_tokens {
+                push_rle_run(&mut body, count, tag);
+            }
         }
 
-        self.living_cells = next_generation;
+        body.push('!');
+
+        format!(
+            "x = {}, y = {}, rule = {}\n{}",
+            self.cols,
+            self.rows,
+            self.rule.to_rulestring(),
+            body
+        )
     }
 
-    pub fn is_live(&self, row_index: usize, col_index: usize) -> bool {
-        self.living_cells
-            .contains_key(&build_living_cell_key(row_index, col_index))
+    /// Saves a sparse snapshot of this world (dimensions, rule, state count,
+    /// and the positions and states of live and dying cells) to `path` as JSON.
+    pub fn save_to_path(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|_| SAVE_ERROR.to_string())?;
+        std::fs::write(path, json).map_err(|_| SAVE_ERROR.to_string())
+    }
+
+    /// Loads a world previously written by `save_to_path`.
+    pub fn load_from_path(path: &Path) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path).map_err(|_| LOAD_ERROR.to_string())?;
+        serde_json::from_str(&json).map_err(|_| LOAD_ERROR.to_string())
     }
 }
 
@@ -206,6 +720,162 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_round_trip_save_and_load() {
+        let config = vec![vec![0, 1, 0], vec![0, 0, 1], vec![1, 1, 1]];
+        let world = DullWorld::from_config(config).unwrap();
+
+        let path = std::env::temp_dir().join("dull_life_test_save.json");
+        world.save_to_path(&path).unwrap();
+        let loaded = DullWorld::load_from_path(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut original = world.get_living_cells();
+        let mut round_tripped = loaded.get_living_cells();
+        original.sort();
+        round_tripped.sort();
+
+        assert_eq!(original, round_tripped);
+        assert_eq!(loaded.dimensions(), world.dimensions());
+        assert_eq!(loaded.rule(), world.rule());
+    }
+
+    #[test]
+    fn it_should_reject_snapshot_with_too_few_states() {
+        let path = std::env::temp_dir().join("dull_life_test_invalid_states.json");
+        std::fs::write(
+            &path,
+            r#"{"rows":3,"cols":3,"rule":{"birth":[false,false,false,true,false,false,false,false,false],"survive":[false,false,true,true,false,false,false,false,false]},"states":0,"cells":[]}"#,
+        )
+        .unwrap();
+
+        let result = DullWorld::load_from_path(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_decode_glider_from_rle() {
+        let rle = "x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!";
+        let world = DullWorld::from_rle(rle).unwrap();
+
+        let mut living_cells = world.get_living_cells();
+        living_cells.sort();
+
+        assert_eq!(living_cells, [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)]);
+    }
+
+    #[test]
+    fn it_should_ignore_comment_lines_in_rle() {
+        let rle = "#N Glider\nx = 3, y = 3, rule = B3/S23\nbo$2bo$3o!";
+        let world = DullWorld::from_rle(rle).unwrap();
+
+        assert!(world.is_live(0, 1));
+    }
+
+    #[test]
+    fn it_should_round_trip_rle_encode_and_decode() {
+        let config = vec![vec![0, 1, 0], vec![0, 0, 1], vec![1, 1, 1]];
+        let world = DullWorld::from_config(config).unwrap();
+
+        let rle = world.to_rle();
+        let decoded = DullWorld::from_rle(&rle).unwrap();
+
+        let mut original = world.get_living_cells();
+        let mut round_tripped = decoded.get_living_cells();
+        original.sort();
+        round_tripped.sort();
+
+        assert_eq!(original, round_tripped);
+        assert_eq!(decoded.dimensions(), world.dimensions());
+    }
+
+    #[test]
+    fn it_should_toggle_cell_with_set_cell() {
+        let config = vec![vec![0, 0], vec![0, 0]];
+        let mut world = DullWorld::from_config(config).unwrap();
+
+        world.set_cell(0, 1, true);
+        assert!(world.is_live(0, 1));
+
+        world.set_cell(0, 1, false);
+        assert!(!world.is_live(0, 1));
+    }
+
+    #[test]
+    fn it_should_not_create_world_with_fewer_than_two_states() {
+        let config = vec![vec![0, 0], vec![0, 0]];
+        let result = DullWorld::from_config_with_rule_and_states(config, Rule::default(), 1);
+
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap(), MIN_STATES_ERROR);
+    }
+
+    #[test]
+    fn it_should_age_into_dying_states_when_it_fails_to_survive() {
+        let config = vec![vec![0, 0, 0], vec![0, 1, 0], vec![0, 0, 0]];
+        let mut world =
+            DullWorld::from_config_with_rule_and_states(config, Rule::default(), 4).unwrap();
+
+        world.step();
+        assert!(!world.is_live(1, 1));
+        assert_eq!(world.cell_state(1, 1), 1);
+
+        world.step();
+        assert_eq!(world.cell_state(1, 1), 2);
+
+        world.step();
+        assert_eq!(world.cell_state(1, 1), 3);
+    }
+
+    #[test]
+    fn it_should_not_count_dying_neighbors_towards_birth() {
+        let config = vec![vec![0, 0, 0], vec![0, 1, 0], vec![0, 0, 0]];
+        let mut world =
+            DullWorld::from_config_with_rule_and_states(config, Rule::default(), 4).unwrap();
+
+        world.step();
+        assert_eq!(world.get_living_cells(), []);
+
+        world.step();
+        assert_eq!(world.get_living_cells(), []);
+    }
+
+    #[test]
+    fn it_should_parse_conway_rulestring() {
+        let rule = Rule::from_rulestring("B3/S23").unwrap();
+        assert_eq!(rule, Rule::conway());
+    }
+
+    #[test]
+    fn it_should_parse_highlife_rulestring() {
+        let rule = Rule::from_rulestring("B36/S23").unwrap();
+        assert!(rule.birth[3] && rule.birth[6]);
+        assert!(rule.survive[2] && rule.survive[3]);
+        assert!(!rule.birth[2] && !rule.survive[6]);
+    }
+
+    #[test]
+    fn it_should_not_parse_invalid_rulestring() {
+        let result = Rule::from_rulestring("B3S23");
+        assert!(result.is_err());
+        assert_eq!(result.err().unwrap(), INVALID_RULESTRING_ERROR);
+    }
+
+    #[test]
+    fn it_should_birth_on_six_neighbours_under_highlife_rule() {
+        let rule = Rule::from_rulestring("B36/S23").unwrap();
+        // Six of the centre's eight neighbours are live (everything but the
+        // bottom-middle and bottom-right cells), so the centre gets a birth under B36 but
+        // would not under plain Conway B3.
+        let config = vec![vec![1, 1, 1], vec![1, 0, 1], vec![1, 0, 0]];
+        let mut world = DullWorld::from_config_with_rule(config, rule).unwrap();
+        world.step();
+
+        assert!(world.is_live(1, 1));
+    }
+
     /// Any live cell with fewer than two live neighbours dies, as if caused by underpopulation.
     #[test]
     fn it_should_die_if_less_than_two_live_neighbours() {
@@ -291,4 +961,71 @@ mod test {
 
         assert_eq!(living_cells, [(2, 1), (2, 2), (2, 3)]);
     }
+
+    #[test]
+    fn it_should_round_trip_dense_buffer() {
+        let config = vec![vec![0, 1, 0], vec![0, 0, 1], vec![1, 1, 1]];
+        let world = DullWorld::from_config(config).unwrap();
+
+        let dense = world.to_dense();
+        assert_eq!(dense, [0, 1, 0, 0, 0, 1, 1, 1, 1]);
+
+        let mut reloaded = DullWorld::from_config(vec![vec![0, 0, 0]; 3]).unwrap();
+        reloaded.load_dense(&dense);
+
+        let mut original = world.get_living_cells();
+        let mut round_tripped = reloaded.get_living_cells();
+        original.sort();
+        round_tripped.sort();
+
+        assert_eq!(original, round_tripped);
+    }
+
+    #[test]
+    fn it_should_drop_dying_cells_when_loading_a_dense_buffer() {
+        let config = vec![vec![0, 0, 0], vec![0, 1, 0], vec![0, 0, 0]];
+        let mut world =
+            DullWorld::from_config_with_rule_and_states(config, Rule::default(), 4).unwrap();
+
+        world.step();
+        assert_eq!(world.cell_state(1, 1), 1);
+
+        let dense = world.to_dense();
+        assert_eq!(dense, [0; 9]);
+        world.load_dense(&dense);
+
+        assert_eq!(world.cell_state(1, 1), world.states() - 1);
+    }
+
+    #[test]
+    fn it_should_support_cells_far_outside_the_initial_bounds() {
+        let mut world = DullWorld::from_config(vec![vec![0, 0], vec![0, 0]]).unwrap();
+
+        world.set_cell(1000, -1000, true);
+        assert!(world.is_live(1000, -1000));
+
+        world.step();
+        assert!(!world.is_live(1000, -1000));
+    }
+
+    #[test]
+    fn it_should_not_wrap_neighbours_around_the_initial_bounds() {
+        let mut world = DullWorld::from_config(vec![vec![0, 0, 0], vec![0, 0, 0]]).unwrap();
+
+        // A vertical blinker straddling row -1..=1, entirely outside the
+        // grid the world was configured with. Under the old toroidal grid
+        // this couldn't even be expressed; here it should oscillate into a
+        // horizontal blinker exactly like any other, with no wraparound.
+        world.set_cell(-1, 1, true);
+        world.set_cell(0, 1, true);
+        world.set_cell(1, 1, true);
+
+        world.step();
+
+        assert!(world.is_live(0, 0));
+        assert!(world.is_live(0, 1));
+        assert!(world.is_live(0, 2));
+        assert!(!world.is_live(-1, 1));
+        assert!(!world.is_live(1, 1));
+    }
 }